@@ -1,4 +1,12 @@
 use crate::gf256::Gf256;
+use std::sync::OnceLock;
+
+/// Shared GF(256) tables, built once on first use. The free functions borrow
+/// this instead of rebuilding 766 bytes of log/exp tables on every call.
+fn shared_gf() -> &'static Gf256 {
+    static GF: OnceLock<Gf256> = OnceLock::new();
+    GF.get_or_init(Gf256::default)
+}
 
 #[derive(Debug)]
 pub enum HeccError {
@@ -6,6 +14,7 @@ pub enum HeccError {
     InvalidShardIndex,
     DuplicateIndex,
     NotEnoughShards,
+    TooManyErrors,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -34,19 +43,37 @@ pub fn hecc_encode(m: &[u8], r: &[u8], n: usize) -> Result<Vec<u8>, HeccError> {
     if k == 0 || t == 0 || n < k + t || n > 255 {
         return Err(HeccError::InvalidParams);
     }
+    Ok(encode_impl(shared_gf(), m, r, n))
+}
 
-    let gf = Gf256::default();
-    let mut coeffs = Vec::with_capacity(k + t);
+/// Column-wise Vandermonde encode shared by [`hecc_encode`] and
+/// [`HeccCodec::encode`]. Callers are responsible for validating `m`, `r`, `n`.
+///
+/// For coefficient position `j` the column vector is `[x_i^j for i in 1..=n]`;
+/// we accumulate `coeff_j · column_j` into `out` with one `mul_slice` + XOR per
+/// coefficient, replacing the per-point Horner loop with buffer-wide multiplies
+/// that vectorize.
+fn encode_impl(gf: &Gf256, m: &[u8], r: &[u8], n: usize) -> Vec<u8> {
+    let mut coeffs = Vec::with_capacity(m.len() + r.len());
     coeffs.extend_from_slice(m);
     coeffs.extend_from_slice(r);
 
-    let mut out = Vec::with_capacity(n);
-    for i in 0..n {
-        let x = (i + 1) as u8;
-        let y = poly_eval(&gf, &coeffs, x);
-        out.push(y);
+    let mut out = vec![0u8; n];
+    let mut column = vec![1u8; n]; // column for j = 0 is all ones (x_i^0)
+    let mut scaled = vec![0u8; n];
+    for &coeff in &coeffs {
+        if coeff != 0 {
+            gf.mul_slice(coeff, &column, &mut scaled);
+            for (o, &s) in out.iter_mut().zip(scaled.iter()) {
+                *o ^= s;
+            }
+        }
+        // Advance the column to the next power: column[i] *= x_i = (i + 1).
+        for (i, c) in column.iter_mut().enumerate() {
+            *c = gf.mul(*c, (i + 1) as u8);
+        }
     }
-    Ok(out)
+    out
 }
 
 /// HECC.Dec per MCP whitepaper (Alg. 2).
@@ -81,16 +108,190 @@ pub fn hecc_decode(
         return Err(HeccError::NotEnoughShards);
     }
 
-    let gf = Gf256::default();
+    let gf = shared_gf();
     let xs: Vec<u8> = unique.iter().map(|&(_, idx)| idx as u8).collect();
     let ys: Vec<u8> = unique.iter().map(|&(v, _)| v).collect();
 
-    let coeffs = lagrange_interpolate(&gf, &xs, &ys, need);
+    let coeffs = lagrange_interpolate(gf, &xs, &ys, need);
     let m = coeffs[..k].to_vec();
     let r = coeffs[k..k + t].to_vec();
     Ok((m, r))
 }
 
+/// Result of [`hecc_decode_with_errors`]: the decoded message and randomness
+/// plus the 1-based indices identified as corrupted.
+pub type DecodedWithErrors = (Vec<u8>, Vec<u8>, Vec<usize>);
+
+/// Error-correcting HECC.Dec via Berlekamp–Welch.
+///
+/// Unlike [`hecc_decode`], which assumes every shard value is correct and only
+/// tolerates missing shards, this recovers the message when up to `max_errors`
+/// shard *values* have been silently corrupted. With `K + T` true coefficients
+/// the shreds lie on a polynomial of degree `< K + T`, so `N >= K + T + 2e`
+/// shards suffice to correct `e` errors.
+///
+/// Input shards are `(value, index)` with 1-based `index` in `[1, N]`. Returns
+/// the decoded `(message, randomness)` together with the 1-based indices that
+/// were identified as corrupted (the roots of the error locator). Returns
+/// [`HeccError::TooManyErrors`] when no locator of degree `<= max_errors`
+/// explains the data.
+pub fn hecc_decode_with_errors(
+    shards: &[(u8, usize)],
+    k: usize,
+    t: usize,
+    max_errors: usize,
+) -> Result<DecodedWithErrors, HeccError> {
+    if k == 0 || t == 0 {
+        return Err(HeccError::InvalidParams);
+    }
+    let need = k + t;
+
+    let mut unique: Vec<(u8, usize)> = Vec::with_capacity(shards.len());
+    for &(v, idx) in shards {
+        if idx == 0 || idx > 255 {
+            return Err(HeccError::InvalidShardIndex);
+        }
+        if unique.iter().any(|&(_, i)| i == idx) {
+            return Err(HeccError::DuplicateIndex);
+        }
+        unique.push((v, idx));
+    }
+
+    let gf = shared_gf();
+    let xs: Vec<u8> = unique.iter().map(|&(_, idx)| idx as u8).collect();
+    let ys: Vec<u8> = unique.iter().map(|&(v, _)| v).collect();
+
+    // Try increasing error counts; the smallest locator that cleanly divides
+    // `Q` is the correct one. `e == 0` reduces to plain interpolation.
+    for e in 0..=max_errors {
+        if unique.len() < need + 2 * e {
+            return Err(HeccError::NotEnoughShards);
+        }
+        // Pass *all* collected shards, not just `need + 2e`, so the system is
+        // overdetermined: `gaussian_solve`'s residual-row check then rejects an
+        // under-budgeted `e` and drives the loop to the true locator.
+        if let Some((p, locator)) = berlekamp_welch(gf, &xs, &ys, need, e)
+        {
+            // Identify the corrupted shards as the roots of the locator.
+            let corrupted: Vec<usize> = unique
+                .iter()
+                .filter(|&&(_, idx)| poly_eval(gf, &locator, idx as u8) == 0)
+                .map(|&(_, idx)| idx)
+                .collect();
+            if corrupted.len() > e {
+                continue;
+            }
+            let m = p[..k].to_vec();
+            let r = p[k..k + t].to_vec();
+            return Ok((m, r, corrupted));
+        }
+    }
+
+    Err(HeccError::TooManyErrors)
+}
+
+/// Reusable codec that owns one set of GF(256) tables and amortizes the
+/// interpolation work across many blocks.
+///
+/// [`encode`](HeccCodec::encode) runs the same Vandermonde product as
+/// [`hecc_encode`]. [`decode_with`](HeccCodec::decode_with) builds the Lagrange
+/// interpolation matrix for a fixed surviving-index set *once* and then applies
+/// it to every block as a matrix-vector product, which is the common case when
+/// thousands of blocks share one erasure pattern.
+pub struct HeccCodec {
+    gf: Gf256,
+    params: HeccParams,
+}
+
+impl HeccCodec {
+    pub fn new(params: HeccParams) -> Result<Self, HeccError> {
+        params.validate()?;
+        Ok(HeccCodec {
+            gf: Gf256::default(),
+            params,
+        })
+    }
+
+    /// Encode one block's `(message, randomness)` into `N` shreds.
+    pub fn encode(&self, m: &[u8], r: &[u8]) -> Result<Vec<u8>, HeccError> {
+        if m.len() != self.params.k || r.len() != self.params.t {
+            return Err(HeccError::InvalidParams);
+        }
+        Ok(encode_impl(&self.gf, m, r, self.params.n))
+    }
+
+    /// Decode many blocks that all survive on the same `indices` (1-based,
+    /// length `K + T`). The interpolation matrix is built once and reused; each
+    /// block in `blocks` is the `K + T` shard values aligned with `indices`.
+    /// Returns the `K`-byte message of each block.
+    pub fn decode_with(
+        &self,
+        indices: &[usize],
+        blocks: &[Vec<u8>],
+    ) -> Result<Vec<Vec<u8>>, HeccError> {
+        let need = self.params.k + self.params.t;
+        if indices.len() != need {
+            return Err(HeccError::NotEnoughShards);
+        }
+
+        let mut xs = Vec::with_capacity(need);
+        for &idx in indices {
+            if idx == 0 || idx > 255 {
+                return Err(HeccError::InvalidShardIndex);
+            }
+            if xs.contains(&(idx as u8)) {
+                return Err(HeccError::DuplicateIndex);
+            }
+            xs.push(idx as u8);
+        }
+
+        let matrix = self.interpolation_matrix(&xs, need);
+
+        let mut out = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            if block.len() != need {
+                return Err(HeccError::NotEnoughShards);
+            }
+            let mut coeffs = vec![0u8; need];
+            for (i, coeff) in coeffs.iter_mut().enumerate() {
+                let mut acc = 0u8;
+                for (j, &y) in block.iter().enumerate() {
+                    acc ^= self.gf.mul(matrix[i][j], y);
+                }
+                *coeff = acc;
+            }
+            out.push(coeffs[..self.params.k].to_vec());
+        }
+        Ok(out)
+    }
+
+    /// Build the `need × need` interpolation matrix `M` such that the recovered
+    /// coefficient vector is `M · y`. `M[i][j] = basis_j[i] · denom_j^{-1}`,
+    /// i.e. the Lagrange basis with the per-point `y` factored out.
+    fn interpolation_matrix(&self, xs: &[u8], need: usize) -> Vec<Vec<u8>> {
+        let gf = &self.gf;
+        let mut matrix = vec![vec![0u8; need]; need];
+        for j in 0..need {
+            let mut basis = vec![1u8];
+            let mut denom = 1u8;
+            for m in 0..need {
+                if m == j {
+                    continue;
+                }
+                denom = gf.mul(denom, gf.sub(xs[j], xs[m]));
+                basis = poly_mul(gf, &basis, &[gf.sub(0, xs[m]), 1]);
+            }
+            let inv_denom = gf.inv(denom);
+            for (i, &b) in basis.iter().enumerate() {
+                if i < need {
+                    matrix[i][j] = gf.mul(inv_denom, b);
+                }
+            }
+        }
+        matrix
+    }
+}
+
 fn poly_eval(gf: &Gf256, coeffs: &[u8], x: u8) -> u8 {
     let mut y = 0u8;
     let mut x_pow = 1u8;
@@ -143,3 +344,128 @@ fn poly_mul(gf: &Gf256, a: &[u8], b: &[u8]) -> Vec<u8> {
     }
     out
 }
+
+/// Solve the Berlekamp–Welch system for `e` errors over every given shard (at
+/// least `need + 2e` of them; any extra rows make the system overdetermined),
+/// returning `(P, E)` where `P` is the degree-`< need` message
+/// polynomial and `E` is the monic degree-`e` error locator, or `None` when the
+/// system is singular or `E` does not divide `Q` cleanly.
+///
+/// Unknowns are the `need + e` coefficients of `Q = P·E` (degree `< need + e`)
+/// followed by the `e` low coefficients of the monic `E`. Each shard imposes
+/// `Q(x_i) - y_i·E(x_i) = 0`, i.e. `Σ q_j x_i^j - y_i Σ_{j<e} E_j x_i^j =
+/// y_i·x_i^e`.
+fn berlekamp_welch(gf: &Gf256, xs: &[u8], ys: &[u8], need: usize, e: usize) -> Option<(Vec<u8>, Vec<u8>)> {
+    let q_len = need + e;
+    let cols = q_len + e;
+    let rows = xs.len();
+
+    let mut aug = vec![vec![0u8; cols + 1]; rows];
+    for ((&x, &y), aug_row) in xs.iter().zip(ys.iter()).zip(aug.iter_mut()) {
+        // Q coefficients: + x^j.
+        let mut x_pow = 1u8;
+        for slot in aug_row[..q_len].iter_mut() {
+            *slot = x_pow;
+            x_pow = gf.mul(x_pow, x);
+        }
+        // E coefficients (low e terms): - y·x^j = + y·x^j in GF(256).
+        let mut xe = 1u8;
+        for slot in aug_row[q_len..q_len + e].iter_mut() {
+            *slot = gf.mul(y, xe);
+            xe = gf.mul(xe, x);
+        }
+        // RHS: y·x^e.
+        aug_row[cols] = gf.mul(y, xe);
+    }
+
+    let sol = gaussian_solve(gf, &mut aug, cols)?;
+
+    let q = &sol[..q_len];
+    let mut locator = vec![0u8; e + 1];
+    locator[..e].copy_from_slice(&sol[q_len..]);
+    locator[e] = 1; // monic leading term
+
+    let (mut p, rem) = poly_divmod(gf, q, &locator);
+    if rem.iter().any(|&c| c != 0) {
+        return None;
+    }
+    if p.len() > need && p[need..].iter().any(|&c| c != 0) {
+        return None; // P has degree >= need: not a valid codeword
+    }
+    p.resize(need, 0);
+    Some((p, locator))
+}
+
+/// In-place Gaussian elimination of an `rows × (cols + 1)` augmented system over
+/// GF(256); returns the `cols`-length solution, or `None` if singular.
+fn gaussian_solve(gf: &Gf256, aug: &mut [Vec<u8>], cols: usize) -> Option<Vec<u8>> {
+    let rows = aug.len();
+    let mut pivot_row = 0;
+    let mut where_pivot = vec![usize::MAX; cols];
+    for col in 0..cols {
+        if pivot_row >= rows {
+            break;
+        }
+        let sel = (pivot_row..rows).find(|&r| aug[r][col] != 0)?;
+        aug.swap(pivot_row, sel);
+
+        let inv = gf.inv(aug[pivot_row][col]);
+        for v in aug[pivot_row][col..=cols].iter_mut() {
+            *v = gf.mul(*v, inv);
+        }
+        let pivot = aug[pivot_row].clone();
+        for (r, aug_row) in aug.iter_mut().enumerate() {
+            if r != pivot_row && aug_row[col] != 0 {
+                let factor = aug_row[col];
+                for (v, &p) in aug_row[col..=cols].iter_mut().zip(pivot[col..=cols].iter()) {
+                    *v ^= gf.mul(factor, p);
+                }
+            }
+        }
+        where_pivot[col] = pivot_row;
+        pivot_row += 1;
+    }
+
+    // An overdetermined consistent system leaves all-zero rows below; reject if
+    // any residual row has a non-zero RHS.
+    for aug_row in &aug[pivot_row..] {
+        if aug_row[cols] != 0 {
+            return None;
+        }
+    }
+
+    let mut sol = vec![0u8; cols];
+    for (slot, &pr) in sol.iter_mut().zip(where_pivot.iter()) {
+        if pr == usize::MAX {
+            return None; // under-determined: no unique solution
+        }
+        *slot = aug[pr][cols];
+    }
+    Some(sol)
+}
+
+/// Polynomial long division over GF(256): returns `(quotient, remainder)` for
+/// `num / den`, coefficients low-to-high. `den` must be non-zero.
+fn poly_divmod(gf: &Gf256, num: &[u8], den: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut rem = num.to_vec();
+    let den_deg = den.iter().rposition(|&c| c != 0).unwrap_or(0);
+    let lead_inv = gf.inv(den[den_deg]);
+
+    if rem.len() < den_deg + 1 {
+        return (vec![0u8], rem);
+    }
+    let mut quot = vec![0u8; rem.len() - den_deg];
+    for i in (den_deg..rem.len()).rev() {
+        if rem[i] == 0 {
+            continue;
+        }
+        let factor = gf.mul(rem[i], lead_inv);
+        quot[i - den_deg] = factor;
+        for j in 0..=den_deg {
+            rem[i - den_deg + j] ^= gf.mul(factor, den[j]);
+        }
+    }
+    let rem_deg = rem.iter().rposition(|&c| c != 0).unwrap_or(0);
+    rem.truncate(rem_deg + 1);
+    (quot, rem)
+}
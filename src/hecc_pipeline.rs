@@ -1,4 +1,4 @@
-use crate::hecc_core::{hecc_decode, hecc_encode, HeccParams};
+use crate::hecc_core::{hecc_encode, HeccCodec, HeccParams};
 use rand::RngCore;
 use std::collections::BTreeMap;
 
@@ -9,24 +9,81 @@ pub struct HeccShard {
     pub value: u8,
 }
 
+/// One message block ready for encoding: its index plus the `K`-byte message
+/// slice and freshly drawn `T`-byte randomness.
+struct Block {
+    block: u32,
+    m: Vec<u8>,
+    r: Vec<u8>,
+}
+
 #[derive(Debug)]
 pub enum HeccPipelineError {
     InvalidParams,
     NotEnoughShards { block: u32, have: usize, need: usize },
     InvalidHeader,
     LengthOverflow,
+    TooManyShards { block: u32, have: usize },
 }
 
+/// A block's selected `(indices, values)` column pair, aligned for the codec.
+type Selection = (Vec<usize>, Vec<u8>);
+
 /// High-level API: shred arbitrary bytes into HECC shards with block metadata.
 pub fn hecc_shred_bytes(
     params: HeccParams,
     message: &[u8],
     rng: &mut impl RngCore,
 ) -> Result<Vec<HeccShard>, HeccPipelineError> {
+    let blocks = prepare_blocks(params, message, rng)?;
+
+    let mut shards = Vec::with_capacity(blocks.len() * params.n);
+    for b in &blocks {
+        encode_block(params, b.block, &b.m, &b.r, &mut shards)?;
+    }
+    Ok(shards)
+}
+
+/// Parallel variant of [`hecc_shred_bytes`]: encodes the independent blocks of
+/// a large payload across worker threads. Randomness is still drawn
+/// sequentially from `rng` so output is deterministic; only the encode step is
+/// parallelized. `parallelism` caps the worker-thread count (e.g. the detected
+/// CPU count); `0` lets rayon pick its default.
+#[cfg(feature = "rayon")]
+pub fn hecc_shred_bytes_parallel(
+    params: HeccParams,
+    message: &[u8],
+    rng: &mut impl RngCore,
+    parallelism: usize,
+) -> Result<Vec<HeccShard>, HeccPipelineError> {
+    use rayon::prelude::*;
+
+    let blocks = prepare_blocks(params, message, rng)?;
+
+    let per_block: Vec<Vec<HeccShard>> = run_in_pool(parallelism, || {
+        blocks
+            .par_iter()
+            .map(|b| {
+                let mut shards = Vec::with_capacity(params.n);
+                encode_block(params, b.block, &b.m, &b.r, &mut shards)?;
+                Ok(shards)
+            })
+            .collect::<Result<Vec<_>, HeccPipelineError>>()
+    })?;
+
+    Ok(per_block.into_iter().flatten().collect())
+}
+
+/// Split the length-prefixed payload into fixed-size message blocks, drawing
+/// `T` bytes of randomness per block from `rng`.
+fn prepare_blocks(
+    params: HeccParams,
+    message: &[u8],
+    rng: &mut impl RngCore,
+) -> Result<Vec<Block>, HeccPipelineError> {
     params.validate().map_err(|_| HeccPipelineError::InvalidParams)?;
     let k = params.k;
     let t = params.t;
-    let n = params.n;
 
     if message.len() > u32::MAX as usize {
         return Err(HeccPipelineError::LengthOverflow);
@@ -36,7 +93,7 @@ pub fn hecc_shred_bytes(
     payload.extend_from_slice(&(message.len() as u32).to_be_bytes());
     payload.extend_from_slice(message);
 
-    let mut shards = Vec::new();
+    let mut blocks = Vec::new();
     let mut block: u32 = 0;
     let mut offset = 0;
     while offset < payload.len() {
@@ -47,20 +104,31 @@ pub fn hecc_shred_bytes(
         let mut r = vec![0u8; t];
         rng.fill_bytes(&mut r);
 
-        let encoded = hecc_encode(&m, &r, n).map_err(|_| HeccPipelineError::InvalidParams)?;
-        for (i, &value) in encoded.iter().enumerate() {
-            shards.push(HeccShard {
-                block,
-                index: (i + 1) as u8,
-                value,
-            });
-        }
-
+        blocks.push(Block { block, m, r });
         block = block.wrapping_add(1);
         offset = end;
     }
 
-    Ok(shards)
+    Ok(blocks)
+}
+
+/// Encode a single block's `(m, r)` into `N` shards appended to `out`.
+fn encode_block(
+    params: HeccParams,
+    block: u32,
+    m: &[u8],
+    r: &[u8],
+    out: &mut Vec<HeccShard>,
+) -> Result<(), HeccPipelineError> {
+    let encoded = hecc_encode(m, r, params.n).map_err(|_| HeccPipelineError::InvalidParams)?;
+    for (i, &value) in encoded.iter().enumerate() {
+        out.push(HeccShard {
+            block,
+            index: (i + 1) as u8,
+            value,
+        });
+    }
+    Ok(())
 }
 
 /// High-level API: recover original bytes from HECC shards.
@@ -69,17 +137,83 @@ pub fn hecc_recover_bytes(
     shards: &[HeccShard],
 ) -> Result<Vec<u8>, HeccPipelineError> {
     params.validate().map_err(|_| HeccPipelineError::InvalidParams)?;
-    let k = params.k;
-    let t = params.t;
-    let need = k + t;
 
+    let by_block = group_by_block(shards);
+    let selections = select_all(params, &by_block)?;
+
+    let codec = HeccCodec::new(params).map_err(|_| HeccPipelineError::InvalidParams)?;
+    let mut recovered = Vec::new();
+    for (indices, positions) in group_by_indices(&selections) {
+        let values: Vec<Vec<u8>> = positions.iter().map(|&p| selections[p].1.clone()).collect();
+        let msgs = codec
+            .decode_with(&indices, &values)
+            .map_err(|_| HeccPipelineError::InvalidParams)?;
+        // Groups are processed out of block order; defer assembly until all
+        // messages are placed back by position below.
+        for (pos, msg) in positions.into_iter().zip(msgs) {
+            recovered.push((pos, msg));
+        }
+    }
+
+    assemble(flatten_in_order(recovered, selections.len()))
+}
+
+/// Parallel variant of [`hecc_recover_bytes`]: decodes the per-index-set shard
+/// groups across worker threads while preserving block ordering in the output.
+/// `parallelism` caps the worker-thread count; `0` lets rayon pick its default.
+#[cfg(feature = "rayon")]
+pub fn hecc_recover_bytes_parallel(
+    params: HeccParams,
+    shards: &[HeccShard],
+    parallelism: usize,
+) -> Result<Vec<u8>, HeccPipelineError> {
+    use rayon::prelude::*;
+
+    params.validate().map_err(|_| HeccPipelineError::InvalidParams)?;
+
+    let by_block = group_by_block(shards);
+    let selections = select_all(params, &by_block)?;
+    let groups = group_by_indices(&selections);
+    let codec = HeccCodec::new(params).map_err(|_| HeccPipelineError::InvalidParams)?;
+
+    let placed: Vec<Vec<(usize, Vec<u8>)>> = run_in_pool(parallelism, || {
+        groups
+            .par_iter()
+            .map(|(indices, positions)| {
+                let values: Vec<Vec<u8>> =
+                    positions.iter().map(|&p| selections[p].1.clone()).collect();
+                let msgs = codec
+                    .decode_with(indices, &values)
+                    .map_err(|_| HeccPipelineError::InvalidParams)?;
+                Ok(positions.iter().copied().zip(msgs).collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>, HeccPipelineError>>()
+    })?;
+
+    assemble(flatten_in_order(
+        placed.into_iter().flatten().collect(),
+        selections.len(),
+    ))
+}
+
+/// Group shards by block index, preserving ascending block order.
+fn group_by_block(shards: &[HeccShard]) -> BTreeMap<u32, Vec<HeccShard>> {
     let mut by_block: BTreeMap<u32, Vec<HeccShard>> = BTreeMap::new();
     for shard in shards {
         by_block.entry(shard.block).or_default().push(*shard);
     }
+    by_block
+}
 
-    let mut recovered = Vec::new();
-    for (block, mut list) in by_block {
+/// For each block (in ascending order) pick the first `K + T` distinct shard
+/// indices, returning `(indices, values)` aligned column-wise for the codec.
+fn select_all(
+    params: HeccParams,
+    by_block: &BTreeMap<u32, Vec<HeccShard>>,
+) -> Result<Vec<Selection>, HeccPipelineError> {
+    let need = params.k + params.t;
+    let mut out = Vec::with_capacity(by_block.len());
+    for (&block, list) in by_block {
         if list.len() < need {
             return Err(HeccPipelineError::NotEnoughShards {
                 block,
@@ -89,31 +223,56 @@ pub fn hecc_recover_bytes(
         }
 
         let mut seen = [false; 256];
-        let mut pairs = Vec::with_capacity(need);
-        for shard in list.drain(..) {
+        let mut indices = Vec::with_capacity(need);
+        let mut values = Vec::with_capacity(need);
+        for shard in list {
             let idx = shard.index as usize;
             if idx == 0 || idx > 255 || seen[idx] {
                 continue;
             }
             seen[idx] = true;
-            pairs.push((shard.value, idx));
-            if pairs.len() == need {
+            indices.push(idx);
+            values.push(shard.value);
+            if indices.len() == need {
                 break;
             }
         }
 
-        if pairs.len() < need {
+        if indices.len() < need {
             return Err(HeccPipelineError::NotEnoughShards {
                 block,
-                have: pairs.len(),
+                have: indices.len(),
                 need,
             });
         }
+        out.push((indices, values));
+    }
+    Ok(out)
+}
 
-        let (m, _) = hecc_decode(&pairs, k, t).map_err(|_| HeccPipelineError::InvalidParams)?;
-        recovered.extend_from_slice(&m);
+/// Bucket block selections by their surviving-index set, so every block sharing
+/// one erasure pattern decodes through a single cached interpolation matrix.
+/// Each group carries the positions (into `selections`) of its members.
+fn group_by_indices(selections: &[Selection]) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut map: BTreeMap<Vec<usize>, Vec<usize>> = BTreeMap::new();
+    for (pos, (indices, _)) in selections.iter().enumerate() {
+        map.entry(indices.clone()).or_default().push(pos);
     }
+    map.into_iter().collect()
+}
 
+/// Concatenate per-block messages back into payload order given `(position,
+/// message)` pairs produced out of order by the grouped decode.
+fn flatten_in_order(placed: Vec<(usize, Vec<u8>)>, len: usize) -> Vec<u8> {
+    let mut slots: Vec<Vec<u8>> = vec![Vec::new(); len];
+    for (pos, msg) in placed {
+        slots[pos] = msg;
+    }
+    slots.into_iter().flatten().collect()
+}
+
+/// Strip the 4-byte big-endian length prefix and return the original message.
+fn assemble(recovered: Vec<u8>) -> Result<Vec<u8>, HeccPipelineError> {
     if recovered.len() < 4 {
         return Err(HeccPipelineError::InvalidHeader);
     }
@@ -123,3 +282,164 @@ pub fn hecc_recover_bytes(
     }
     Ok(recovered[4..4 + len].to_vec())
 }
+
+/// Magic bytes prefixing the serialized shard container.
+const SHARD_MAGIC: [u8; 2] = *b"HC";
+/// Wire-format version for [`serialize_shards`] / [`deserialize_shards`].
+const SHARD_FORMAT_VERSION: u8 = 1;
+
+/// Serialize `params` and `shards` into a single self-describing blob.
+///
+/// Layout: a fixed header (`"HC"`, version byte, then `k`, `t`, `n` as single
+/// bytes — each fits since `n <= 255`), followed by one record per block in
+/// ascending order. Each record is a varint delta from the previous block
+/// number, a single-byte entry count, then that many `(index, value)` byte
+/// pairs. Deltas keep block numbers compact for dense, contiguous shard sets.
+///
+/// Returns [`HeccPipelineError::TooManyShards`] if any one block has more than
+/// 255 shards, since the per-block entry count is a single byte; callers
+/// feeding in shards from an untrusted source (e.g. a transport layer) should
+/// deduplicate/cap per-block shards before serializing.
+pub fn serialize_shards(
+    params: &HeccParams,
+    shards: &[HeccShard],
+) -> Result<Vec<u8>, HeccPipelineError> {
+    let by_block = group_by_block(shards);
+    for (&block, list) in &by_block {
+        if list.len() > 255 {
+            return Err(HeccPipelineError::TooManyShards {
+                block,
+                have: list.len(),
+            });
+        }
+    }
+
+    let mut out = Vec::with_capacity(4 + 4 + shards.len() * 2);
+    out.extend_from_slice(&SHARD_MAGIC);
+    out.push(SHARD_FORMAT_VERSION);
+    out.push(params.k as u8);
+    out.push(params.t as u8);
+    out.push(params.n as u8);
+
+    let mut prev: u32 = 0;
+    for (block, list) in &by_block {
+        write_varint(&mut out, (block - prev) as u64);
+        prev = *block;
+        out.push(list.len() as u8);
+        for shard in list {
+            out.push(shard.index);
+            out.push(shard.value);
+        }
+    }
+    Ok(out)
+}
+
+/// Inverse of [`serialize_shards`]: reconstruct the [`HeccParams`] and shards
+/// from a blob, so received bytes can be fed straight into
+/// [`hecc_recover_bytes`] without tracking parameters out of band.
+pub fn deserialize_shards(
+    bytes: &[u8],
+) -> Result<(HeccParams, Vec<HeccShard>), HeccPipelineError> {
+    if bytes.len() < 6 || bytes[0..2] != SHARD_MAGIC || bytes[2] != SHARD_FORMAT_VERSION {
+        return Err(HeccPipelineError::InvalidHeader);
+    }
+    let params = HeccParams {
+        k: bytes[3] as usize,
+        t: bytes[4] as usize,
+        n: bytes[5] as usize,
+    };
+    params.validate().map_err(|_| HeccPipelineError::InvalidParams)?;
+
+    let mut pos = 6;
+    let mut block: u32 = 0;
+    let mut shards = Vec::new();
+    let mut first = true;
+    while pos < bytes.len() {
+        let (delta, next) = read_varint(bytes, pos).ok_or(HeccPipelineError::InvalidHeader)?;
+        pos = next;
+        if first {
+            block = delta as u32;
+            first = false;
+        } else {
+            block = block
+                .checked_add(delta as u32)
+                .ok_or(HeccPipelineError::InvalidHeader)?;
+        }
+
+        if pos >= bytes.len() {
+            return Err(HeccPipelineError::InvalidHeader);
+        }
+        let count = bytes[pos] as usize;
+        pos += 1;
+
+        if pos + count * 2 > bytes.len() {
+            return Err(HeccPipelineError::InvalidHeader);
+        }
+        for _ in 0..count {
+            shards.push(HeccShard {
+                block,
+                index: bytes[pos],
+                value: bytes[pos + 1],
+            });
+            pos += 2;
+        }
+    }
+
+    Ok((params, shards))
+}
+
+/// Append `value` to `out` as an unsigned LEB128 varint.
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Read an unsigned LEB128 varint starting at `pos`, returning `(value,
+/// next_pos)` or `None` on a truncated or over-long encoding.
+fn read_varint(bytes: &[u8], pos: usize) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    let mut i = pos;
+    loop {
+        let byte = *bytes.get(i)?;
+        i += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, i));
+        }
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+}
+
+/// Run `f` on a rayon pool capped at `parallelism` threads, or the global pool
+/// when `parallelism == 0`. Falls back to the caller's thread if a bounded pool
+/// cannot be built.
+#[cfg(feature = "rayon")]
+fn run_in_pool<T, F>(parallelism: usize, f: F) -> T
+where
+    F: FnOnce() -> T + Send,
+    T: Send,
+{
+    if parallelism == 0 {
+        return f();
+    }
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(parallelism)
+        .build()
+    {
+        Ok(pool) => pool.install(f),
+        Err(_) => f(),
+    }
+}
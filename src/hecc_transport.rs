@@ -0,0 +1,211 @@
+//! Shard distribution/collection transport built on the shred/recover pipeline.
+//!
+//! The codec turns a payload into `N` shards per block; this layer maps those
+//! shards onto `N` independent lanes (network peers, object-store keys, disks)
+//! and, on the read side, gathers the first `K + T` shards that arrive per
+//! block before handing off to recovery. Because the hiding property protects
+//! each shard individually, a lane sees nothing about the plaintext.
+//!
+//! A blocking API ([`ShardSink`]/[`ShardSource`] + [`distribute`]/[`recover`])
+//! is always available; a futures-based mirror lives behind the `async`
+//! feature.
+
+use crate::hecc_core::HeccParams;
+use crate::hecc_pipeline::{hecc_recover_bytes, hecc_shred_bytes, HeccPipelineError, HeccShard};
+use rand::RngCore;
+use std::collections::BTreeMap;
+
+/// Error surfaced by the transport driver, parameterized over the backend's own
+/// error type `E`.
+#[derive(Debug)]
+pub enum TransportError<E> {
+    /// The underlying codec/pipeline rejected the request.
+    Pipeline(HeccPipelineError),
+    /// A sink or source backend failed.
+    Backend(E),
+    /// A lane count that does not match `params.n`.
+    LaneCountMismatch { expected: usize, got: usize },
+    /// Not enough shards could be collected for `block` to recover it.
+    NotEnoughShards { block: u32, have: usize, need: usize },
+}
+
+impl<E> From<HeccPipelineError> for TransportError<E> {
+    fn from(err: HeccPipelineError) -> Self {
+        match err {
+            HeccPipelineError::NotEnoughShards { block, have, need } => {
+                TransportError::NotEnoughShards { block, have, need }
+            }
+            other => TransportError::Pipeline(other),
+        }
+    }
+}
+
+/// A destination for one shard lane (all shards sharing an `index`).
+pub trait ShardSink {
+    type Error;
+    /// Persist/transmit a lane's shards.
+    fn store(&mut self, shards: &[HeccShard]) -> Result<(), Self::Error>;
+}
+
+/// A retrievable shard lane.
+pub trait ShardSource {
+    type Error;
+    /// Fetch all shards this source holds.
+    fn fetch(&mut self) -> Result<Vec<HeccShard>, Self::Error>;
+}
+
+/// Fan a payload's shards out across `sinks`, routing each shard to the lane
+/// matching its 1-based `index`. `sinks` must have exactly `params.n` entries.
+pub fn distribute<S: ShardSink>(
+    params: HeccParams,
+    payload: &[u8],
+    rng: &mut impl RngCore,
+    sinks: &mut [S],
+) -> Result<(), TransportError<S::Error>> {
+    if sinks.len() != params.n {
+        return Err(TransportError::LaneCountMismatch {
+            expected: params.n,
+            got: sinks.len(),
+        });
+    }
+
+    let shards = hecc_shred_bytes(params, payload, rng)?;
+    let lanes = split_lanes(params, shards);
+    for (sink, lane) in sinks.iter_mut().zip(lanes) {
+        sink.store(&lane).map_err(TransportError::Backend)?;
+    }
+    Ok(())
+}
+
+/// Collect shards from every source and recover the original payload, keeping
+/// at most `K + T` shards per block.
+pub fn recover<R: ShardSource>(
+    params: HeccParams,
+    sources: &mut [R],
+) -> Result<Vec<u8>, TransportError<R::Error>> {
+    let need = params.k + params.t;
+    let mut collected: BTreeMap<u32, Vec<HeccShard>> = BTreeMap::new();
+    for source in sources.iter_mut() {
+        let shards = source.fetch().map_err(TransportError::Backend)?;
+        absorb(&mut collected, shards, need);
+    }
+
+    let all: Vec<HeccShard> = collected.into_values().flatten().collect();
+    Ok(hecc_recover_bytes(params, &all)?)
+}
+
+/// Bucket shards into `N` lanes keyed by `index - 1`.
+fn split_lanes(params: HeccParams, shards: Vec<HeccShard>) -> Vec<Vec<HeccShard>> {
+    let mut lanes: Vec<Vec<HeccShard>> = (0..params.n).map(|_| Vec::new()).collect();
+    for shard in shards {
+        let lane = (shard.index as usize).saturating_sub(1);
+        if lane < lanes.len() {
+            lanes[lane].push(shard);
+        }
+    }
+    lanes
+}
+
+/// Merge freshly fetched shards into the per-block collection, capping each
+/// block at `need` distinct indices so late-arriving duplicates are dropped.
+fn absorb(collected: &mut BTreeMap<u32, Vec<HeccShard>>, shards: Vec<HeccShard>, need: usize) {
+    for shard in shards {
+        let entry = collected.entry(shard.block).or_default();
+        if entry.len() < need && !entry.iter().any(|s| s.index == shard.index) {
+            entry.push(shard);
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_transport {
+    use super::*;
+    use futures::future::join_all;
+    use futures::stream::{FuturesUnordered, StreamExt};
+    use std::future::Future;
+
+    /// Async counterpart of [`ShardSink`].
+    ///
+    /// Methods return an explicit `impl Future + Send` rather than using
+    /// `async fn`, which avoids the `async_fn_in_trait` lint and keeps the
+    /// returned futures `Send` so drivers can run them on a work-stealing pool.
+    pub trait AsyncShardSink {
+        type Error;
+        /// Persist/transmit a lane's shards.
+        fn store(
+            &self,
+            shards: Vec<HeccShard>,
+        ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+    }
+
+    /// Async counterpart of [`ShardSource`].
+    pub trait AsyncShardSource {
+        type Error;
+        /// Fetch all shards this source holds.
+        fn fetch(&self) -> impl Future<Output = Result<Vec<HeccShard>, Self::Error>> + Send;
+    }
+
+    /// Fan shards out to all sinks concurrently.
+    pub async fn distribute_async<S: AsyncShardSink>(
+        params: HeccParams,
+        payload: &[u8],
+        rng: &mut impl RngCore,
+        sinks: &[S],
+    ) -> Result<(), TransportError<S::Error>> {
+        if sinks.len() != params.n {
+            return Err(TransportError::LaneCountMismatch {
+                expected: params.n,
+                got: sinks.len(),
+            });
+        }
+
+        let shards = hecc_shred_bytes(params, payload, rng)?;
+        let lanes = split_lanes(params, shards);
+        let results = join_all(
+            sinks
+                .iter()
+                .zip(lanes)
+                .map(|(sink, lane)| sink.store(lane)),
+        )
+        .await;
+        for result in results {
+            result.map_err(TransportError::Backend)?;
+        }
+        Ok(())
+    }
+
+    /// Issue parallel fetches and recover as soon as enough shards land.
+    ///
+    /// Each source holds one shard per block (its lane), so the full block set
+    /// is known once any source has returned. We break as soon as every block
+    /// seen so far has `K + T` distinct indices; dropping `pending` at that
+    /// point cancels the slowest sources still in flight.
+    pub async fn recover_async<R: AsyncShardSource>(
+        params: HeccParams,
+        sources: &[R],
+    ) -> Result<Vec<u8>, TransportError<R::Error>> {
+        let need = params.k + params.t;
+        let mut pending: FuturesUnordered<_> = sources.iter().map(|s| s.fetch()).collect();
+
+        let mut collected: BTreeMap<u32, Vec<HeccShard>> = BTreeMap::new();
+        while let Some(result) = pending.next().await {
+            let shards = result.map_err(TransportError::Backend)?;
+            absorb(&mut collected, shards, need);
+
+            // Stop once every block collected so far is fully recoverable; the
+            // remaining (slower) fetches are cancelled when `pending` drops.
+            if !collected.is_empty() && collected.values().all(|v| v.len() == need) {
+                break;
+            }
+        }
+        drop(pending);
+
+        let all: Vec<HeccShard> = collected.into_values().flatten().collect();
+        Ok(hecc_recover_bytes(params, &all)?)
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_transport::{
+    distribute_async, recover_async, AsyncShardSink, AsyncShardSource,
+};
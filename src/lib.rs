@@ -30,6 +30,22 @@
 mod gf256;
 mod hecc_core;
 mod hecc_pipeline;
+mod hecc_transport;
 
-pub use crate::hecc_core::{hecc_decode, hecc_encode, HeccError, HeccParams};
-pub use crate::hecc_pipeline::{hecc_recover_bytes, hecc_shred_bytes, HeccPipelineError, HeccShard};
+pub use crate::hecc_core::{
+    hecc_decode, hecc_decode_with_errors, hecc_encode, HeccCodec, HeccError, HeccParams,
+};
+pub use crate::hecc_pipeline::{
+    deserialize_shards, hecc_recover_bytes, hecc_shred_bytes, serialize_shards, HeccPipelineError,
+    HeccShard,
+};
+
+#[cfg(feature = "rayon")]
+pub use crate::hecc_pipeline::{hecc_recover_bytes_parallel, hecc_shred_bytes_parallel};
+
+pub use crate::hecc_transport::{distribute, recover, ShardSink, ShardSource, TransportError};
+
+#[cfg(feature = "async")]
+pub use crate::hecc_transport::{
+    distribute_async, recover_async, AsyncShardSink, AsyncShardSource,
+};
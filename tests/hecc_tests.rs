@@ -1,5 +1,7 @@
 use hecc::{
-    hecc_decode, hecc_encode, hecc_recover_bytes, hecc_shred_bytes, HeccParams,
+    deserialize_shards, distribute, hecc_decode, hecc_decode_with_errors, hecc_encode,
+    hecc_recover_bytes, hecc_shred_bytes, recover, serialize_shards, HeccCodec, HeccParams,
+    ShardSink, ShardSource,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rand::seq::SliceRandom;
@@ -45,6 +47,79 @@ fn hecc_roundtrip_random() {
     }
 }
 
+#[test]
+fn hecc_decode_corrects_errors() {
+    let mut rng = StdRng::seed_from_u64(77);
+    let k = 8;
+    let t = 3;
+    let e = 2;
+    let n = k + t + 2 * e; // N >= K + T + 2e
+    for _ in 0..25 {
+        let m: Vec<u8> = (0..k).map(|_| rng.gen()).collect();
+        let r: Vec<u8> = (0..t).map(|_| rng.gen()).collect();
+        let shreds = hecc_encode(&m, &r, n).expect("encode");
+
+        let mut shards: Vec<(u8, usize)> =
+            shreds.iter().enumerate().map(|(i, &v)| (v, i + 1)).collect();
+
+        // Corrupt `e` distinct shard values.
+        let mut positions: Vec<usize> = (0..n).collect();
+        positions.shuffle(&mut rng);
+        let mut corrupted: Vec<usize> = Vec::new();
+        for &pos in positions.iter().take(e) {
+            let delta: u8 = rng.gen_range(1..=255);
+            shards[pos].0 ^= delta;
+            corrupted.push(pos + 1);
+        }
+        corrupted.sort_unstable();
+
+        let (m2, r2, mut found) =
+            hecc_decode_with_errors(&shards, k, t, e).expect("decode with errors");
+        found.sort_unstable();
+        assert_eq!(m2, m);
+        assert_eq!(r2, r);
+        assert_eq!(found, corrupted);
+    }
+}
+
+#[test]
+fn hecc_decode_with_errors_too_many() {
+    let m = vec![1u8, 2, 3, 4];
+    let r = vec![5u8, 6];
+    let n = 12;
+    let shreds = hecc_encode(&m, &r, n).expect("encode");
+    let mut shards: Vec<(u8, usize)> =
+        shreds.iter().enumerate().map(|(i, &v)| (v, i + 1)).collect();
+    // Two corruptions but only one error budgeted.
+    shards[0].0 ^= 0x5a;
+    shards[1].0 ^= 0x31;
+    assert!(hecc_decode_with_errors(&shards, m.len(), r.len(), 1).is_err());
+}
+
+#[test]
+fn hecc_codec_matches_free_functions() {
+    let params = HeccParams { k: 10, t: 4, n: 20 };
+    let codec = HeccCodec::new(params).expect("codec");
+    let mut rng = StdRng::seed_from_u64(31337);
+
+    // One fixed surviving-index set shared across many blocks.
+    let indices: Vec<usize> = (1..=params.k + params.t).collect();
+
+    let mut messages = Vec::new();
+    let mut groups = Vec::new();
+    for _ in 0..50 {
+        let m: Vec<u8> = (0..params.k).map(|_| rng.gen()).collect();
+        let r: Vec<u8> = (0..params.t).map(|_| rng.gen()).collect();
+        let shreds = codec.encode(&m, &r).expect("encode");
+        assert_eq!(shreds, hecc_encode(&m, &r, params.n).expect("free encode"));
+        groups.push(indices.iter().map(|&i| shreds[i - 1]).collect::<Vec<u8>>());
+        messages.push(m);
+    }
+
+    let decoded = codec.decode_with(&indices, &groups).expect("decode_with");
+    assert_eq!(decoded, messages);
+}
+
 #[test]
 fn hecc_not_enough_shards() {
     let m = vec![1u8, 2, 3, 4];
@@ -117,6 +192,193 @@ fn hecc_invalid_index_rejected() {
     assert!(hecc_decode(&shards, m.len(), r.len()).is_err());
 }
 
+#[test]
+fn hecc_shard_wire_format_roundtrip() {
+    let params = HeccParams { k: 12, t: 5, n: 24 };
+    let mut rng = StdRng::seed_from_u64(4242);
+    let message: Vec<u8> = (0..300).map(|_| rng.gen()).collect();
+
+    let shards = hecc_shred_bytes(params, &message, &mut rng).expect("shred");
+    let blob = serialize_shards(&params, &shards).expect("serialize");
+
+    let (params2, shards2) = deserialize_shards(&blob).expect("deserialize");
+    assert_eq!(params2.k, params.k);
+    assert_eq!(params2.t, params.t);
+    assert_eq!(params2.n, params.n);
+    assert_eq!(shards2, shards);
+
+    // Recover straight from the deserialized shards.
+    let recovered = hecc_recover_bytes(params2, &shards2).expect("recover");
+    assert_eq!(recovered, message);
+}
+
+#[test]
+fn hecc_deserialize_rejects_garbage() {
+    assert!(deserialize_shards(b"").is_err());
+    assert!(deserialize_shards(b"XX\x01\x08\x04\x10").is_err());
+}
+
+#[test]
+fn hecc_serialize_rejects_too_many_shards_per_block() {
+    let params = HeccParams { k: 4, t: 2, n: 8 };
+    // More than 255 entries for a single block, as an untrusted transport
+    // might hand off before any K+T capping has happened.
+    let shards: Vec<hecc::HeccShard> = (0..300)
+        .map(|i| hecc::HeccShard {
+            block: 0,
+            index: 1,
+            value: i as u8,
+        })
+        .collect();
+    let err = serialize_shards(&params, &shards).err();
+    assert!(matches!(
+        err,
+        Some(hecc::HeccPipelineError::TooManyShards { block: 0, have: 300 })
+    ));
+}
+
+#[derive(Default)]
+struct MemLane {
+    shards: Vec<hecc::HeccShard>,
+}
+
+impl ShardSink for MemLane {
+    type Error = std::convert::Infallible;
+    fn store(&mut self, shards: &[hecc::HeccShard]) -> Result<(), Self::Error> {
+        self.shards.extend_from_slice(shards);
+        Ok(())
+    }
+}
+
+impl ShardSource for MemLane {
+    type Error = std::convert::Infallible;
+    fn fetch(&mut self) -> Result<Vec<hecc::HeccShard>, Self::Error> {
+        Ok(self.shards.clone())
+    }
+}
+
+#[test]
+fn hecc_transport_roundtrip() {
+    let params = HeccParams { k: 10, t: 4, n: 20 };
+    let mut rng = StdRng::seed_from_u64(7);
+    let message: Vec<u8> = (0..250).map(|_| rng.gen()).collect();
+
+    let mut lanes: Vec<MemLane> = (0..params.n).map(|_| MemLane::default()).collect();
+    distribute(params, &message, &mut rng, &mut lanes).expect("distribute");
+
+    // Drop a few whole lanes; K + T of the N remain per block.
+    let mut surviving: Vec<MemLane> = lanes.into_iter().take(params.k + params.t).collect();
+    let recovered = recover(params, &mut surviving).expect("recover");
+    assert_eq!(recovered, message);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn hecc_async_transport_roundtrip() {
+    use futures::executor::block_on;
+    use hecc::{distribute_async, recover_async, AsyncShardSink, AsyncShardSource};
+    use std::convert::Infallible;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Mutex;
+    use std::task::{Context, Poll};
+
+    /// A future that yields `remaining` times before resolving, simulating
+    /// sources answering at different speeds without relying on wall-clock
+    /// timers (so the test stays deterministic).
+    struct Latency {
+        remaining: usize,
+    }
+
+    impl Future for Latency {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.remaining == 0 {
+                Poll::Ready(())
+            } else {
+                self.remaining -= 1;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    /// A lane that records whether it was ever polled to completion, so the
+    /// test can confirm slow lanes get cancelled once enough fast ones land.
+    struct LatentLane {
+        shards: Mutex<Vec<hecc::HeccShard>>,
+        delay: usize,
+        fetched: Mutex<bool>,
+    }
+
+    impl AsyncShardSink for LatentLane {
+        type Error = Infallible;
+        async fn store(&self, shards: Vec<hecc::HeccShard>) -> Result<(), Self::Error> {
+            *self.shards.lock().unwrap() = shards;
+            Ok(())
+        }
+    }
+
+    impl AsyncShardSource for LatentLane {
+        type Error = Infallible;
+        async fn fetch(&self) -> Result<Vec<hecc::HeccShard>, Self::Error> {
+            Latency { remaining: self.delay }.await;
+            *self.fetched.lock().unwrap() = true;
+            Ok(self.shards.lock().unwrap().clone())
+        }
+    }
+
+    let params = HeccParams { k: 10, t: 4, n: 20 };
+    let mut rng = StdRng::seed_from_u64(2026);
+    let message: Vec<u8> = (0..250).map(|_| rng.gen()).collect();
+
+    // Lanes answer in reverse-index order: the last lanes are fastest, so
+    // recovery should complete (and cancel the rest) well before the first
+    // lanes would ever resolve.
+    let lanes: Vec<LatentLane> = (0..params.n)
+        .map(|i| LatentLane {
+            shards: Mutex::new(Vec::new()),
+            delay: params.n - i,
+            fetched: Mutex::new(false),
+        })
+        .collect();
+
+    block_on(distribute_async(params, &message, &mut rng, &lanes)).expect("distribute_async");
+    let recovered = block_on(recover_async(params, &lanes)).expect("recover_async");
+    assert_eq!(recovered, message);
+
+    let fetched_count = lanes.iter().filter(|l| *l.fetched.lock().unwrap()).count();
+    assert!(
+        fetched_count < params.n,
+        "recover_async should cancel slower sources once K + T shards land per block"
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn hecc_parallel_matches_sequential() {
+    use hecc::{hecc_recover_bytes_parallel, hecc_shred_bytes_parallel};
+
+    let params = HeccParams { k: 16, t: 6, n: 32 };
+    let message: Vec<u8> = {
+        let mut rng = StdRng::seed_from_u64(55);
+        (0..5000).map(|_| rng.gen()).collect()
+    };
+
+    // Same rng seed on both paths: randomness is drawn sequentially, so the
+    // parallel output must be byte-identical to the sequential one.
+    let mut rng_seq = StdRng::seed_from_u64(88);
+    let mut rng_par = StdRng::seed_from_u64(88);
+    let seq = hecc_shred_bytes(params, &message, &mut rng_seq).expect("shred seq");
+    let par = hecc_shred_bytes_parallel(params, &message, &mut rng_par, 4).expect("shred par");
+    assert_eq!(par, seq, "parallel shredding must preserve block ordering");
+
+    let rec_seq = hecc_recover_bytes(params, &seq).expect("recover seq");
+    let rec_par = hecc_recover_bytes_parallel(params, &par, 4).expect("recover par");
+    assert_eq!(rec_seq, message);
+    assert_eq!(rec_par, message);
+}
+
 #[test]
 fn hecc_pipeline_missing_block_fails() {
     let params = HeccParams { k: 8, t: 4, n: 16 };